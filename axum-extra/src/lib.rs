@@ -0,0 +1,3 @@
+//! Extra utilities for axum.
+
+pub mod response;
@@ -30,13 +30,40 @@ use serde::Serialize;
 ///     }
 /// }
 /// ```
+///
+/// Rust doesn't allow overloading `pretty` as both an associated function and a method on the
+/// same type, so the pretty-printing toggle lives on `new`'s return value rather than
+/// alongside it: use `ErasedJson::new(val).pretty(true)`.
 #[derive(Debug)]
-pub struct ErasedJson(serde_json::Result<Vec<u8>>);
+pub struct ErasedJson {
+    result: serde_json::Result<Vec<u8>>,
+    pretty: bool,
+}
 
 impl ErasedJson {
     /// Create an `ErasedJson` by serializing a value.
     pub fn new<T: Serialize>(val: T) -> Self {
-        Self(serde_json::to_vec(&val))
+        Self {
+            result: serde_json::to_vec(&val),
+            pretty: false,
+        }
+    }
+
+    /// Toggle pretty-printing of the JSON output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use axum::{response::IntoResponse};
+    /// # use axum_extra::response::ErasedJson;
+    /// async fn handler() -> impl IntoResponse {
+    ///     # let foo = ();
+    ///     ErasedJson::new(&foo).pretty(true)
+    /// }
+    /// ```
+    pub fn pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
     }
 }
 
@@ -48,8 +75,8 @@ impl IntoResponse for ErasedJson {
         #[allow(clippy::declare_interior_mutable_const)]
         const APPLICATION_JSON: HeaderValue = HeaderValue::from_static("application/json");
 
-        let bytes = match self.0 {
-            Ok(res) => res,
+        let compact = match self.result {
+            Ok(bytes) => bytes,
             Err(err) => {
                 return Response::builder()
                     .status(StatusCode::INTERNAL_SERVER_ERROR)
@@ -59,9 +86,116 @@ impl IntoResponse for ErasedJson {
             }
         };
 
+        let bytes = if self.pretty {
+            reindent_pretty(&compact)
+        } else {
+            compact
+        };
+
         let mut res = Response::new(Full::from(bytes));
         res.headers_mut()
             .insert(header::CONTENT_TYPE, APPLICATION_JSON);
         res
     }
 }
+
+/// Re-indent an already-serialized compact JSON byte string into the same two-space-indented
+/// layout `serde_json::to_vec_pretty` produces.
+///
+/// Unlike parsing through a `serde_json::Value` (which loses field order without the
+/// `preserve_order` feature), this works directly on the compact bytes `serde_json::to_vec`
+/// already produced, so the original field order from `T`'s `Serialize` impl is preserved
+/// byte-for-byte.
+fn reindent_pretty(compact: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(compact.len() * 2);
+    let mut indent: usize = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    let mut bytes = compact.iter().copied().peekable();
+    while let Some(b) = bytes.next() {
+        if in_string {
+            out.push(b);
+            if escaped {
+                escaped = false;
+            } else if b == b'\\' {
+                escaped = true;
+            } else if b == b'"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match b {
+            b'"' => {
+                in_string = true;
+                out.push(b);
+            }
+            b'{' | b'[' => {
+                let closing = if b == b'{' { b'}' } else { b']' };
+                out.push(b);
+                if bytes.peek() == Some(&closing) {
+                    out.push(bytes.next().unwrap());
+                } else {
+                    indent += 1;
+                    out.push(b'\n');
+                    out.resize(out.len() + indent * 2, b' ');
+                }
+            }
+            b'}' | b']' => {
+                indent = indent.saturating_sub(1);
+                out.push(b'\n');
+                out.resize(out.len() + indent * 2, b' ');
+                out.push(b);
+            }
+            b',' => {
+                out.push(b);
+                out.push(b'\n');
+                out.resize(out.len() + indent * 2, b' ');
+            }
+            b':' => {
+                out.push(b);
+                out.push(b' ');
+            }
+            _ => out.push(b),
+        }
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pretty_toggle_formats_with_newlines() {
+        assert_eq!(reindent_pretty(br#"{"a":1}"#), b"{\n  \"a\": 1\n}");
+    }
+
+    #[test]
+    fn pretty_preserves_declaration_order() {
+        // Field order must come straight from the compact bytes, not a re-sorted
+        // serde_json::Value, so "z" stays before "a".
+        assert_eq!(
+            reindent_pretty(br#"{"z":1,"a":2}"#),
+            b"{\n  \"z\": 1,\n  \"a\": 2\n}"
+        );
+    }
+
+    #[test]
+    fn pretty_handles_nested_empty_containers() {
+        assert_eq!(
+            reindent_pretty(br#"{"a":{},"b":[]}"#),
+            b"{\n  \"a\": {},\n  \"b\": []\n}"
+        );
+    }
+
+    #[test]
+    fn pretty_ignores_structural_bytes_inside_strings() {
+        assert_eq!(
+            reindent_pretty(br#"{"a":"{,}:[]"}"#),
+            b"{\n  \"a\": \"{,}:[]\"\n}"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use std::convert::Infallible;
+
+use axum::{
+    body::{Bytes, Full},
+    http::{header, HeaderValue, Response, StatusCode},
+    response::IntoResponse,
+};
+use serde::Serialize;
+
+/// A response type that holds a value serialized to one of several wire formats.
+///
+/// This is the multi-format counterpart to [`ErasedJson`](super::ErasedJson): it lets a
+/// handler pick a binary (or text) encoding per branch, rather than committing to JSON.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "erased-cbor")]
+/// # {
+/// # use axum::{response::IntoResponse};
+/// # use axum_extra::response::ErasedResponse;
+/// async fn handler() -> impl IntoResponse {
+///     # let condition = true;
+///     # let foo = ();
+///     # let bar = vec![()];
+///     // ...
+///
+///     if condition {
+///         ErasedResponse::cbor(&foo)
+///     } else {
+///         ErasedResponse::cbor(&bar)
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ErasedResponse {
+    result: Result<Vec<u8>, String>,
+    content_type: &'static str,
+}
+
+impl ErasedResponse {
+    /// Create an `ErasedResponse` by serializing a value as JSON.
+    pub fn json<T: Serialize>(val: T) -> Self {
+        Self {
+            result: serde_json::to_vec(&val).map_err(|err| err.to_string()),
+            content_type: "application/json",
+        }
+    }
+
+    /// Create an `ErasedResponse` by serializing a value as CBOR.
+    #[cfg(feature = "erased-cbor")]
+    pub fn cbor<T: Serialize>(val: T) -> Self {
+        let result = {
+            let mut buf = Vec::new();
+            ciborium::ser::into_writer(&val, &mut buf)
+                .map(|_| buf)
+                .map_err(|err| err.to_string())
+        };
+
+        Self {
+            result,
+            content_type: "application/cbor",
+        }
+    }
+
+    /// Create an `ErasedResponse` by serializing a value as MessagePack.
+    #[cfg(feature = "erased-msgpack")]
+    pub fn msgpack<T: Serialize>(val: T) -> Self {
+        Self {
+            result: rmp_serde::to_vec(&val).map_err(|err| err.to_string()),
+            content_type: "application/msgpack",
+        }
+    }
+
+    /// Create an `ErasedResponse` by serializing a value as YAML.
+    #[cfg(feature = "erased-yaml")]
+    pub fn yaml<T: Serialize>(val: T) -> Self {
+        Self {
+            result: serde_yaml::to_vec(&val).map_err(|err| err.to_string()),
+            content_type: "application/yaml",
+        }
+    }
+}
+
+impl IntoResponse for ErasedResponse {
+    type Body = Full<Bytes>;
+    type BodyError = Infallible;
+
+    fn into_response(self) -> Response<Self::Body> {
+        let bytes = match self.result {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                return Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .header(header::CONTENT_TYPE, "text/plain")
+                    .body(Full::from(err))
+                    .unwrap();
+            }
+        };
+
+        let mut res = Response::new(Full::from(bytes));
+        res.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(self.content_type),
+        );
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::HttpBody;
+
+    async fn body_bytes(res: Response<Full<Bytes>>) -> Vec<u8> {
+        res.into_body().data().await.unwrap().unwrap().to_vec()
+    }
+
+    #[tokio::test]
+    async fn json_round_trips() {
+        let res = ErasedResponse::json(serde_json::json!({ "a": 1 })).into_response();
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(body_bytes(res).await, br#"{"a":1}"#);
+    }
+
+    #[cfg(feature = "erased-cbor")]
+    #[tokio::test]
+    async fn cbor_round_trips() {
+        let res = ErasedResponse::cbor(serde_json::json!({ "a": 1 })).into_response();
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/cbor");
+        let bytes = body_bytes(res).await;
+        let value: serde_json::Value = ciborium::de::from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(value, serde_json::json!({ "a": 1 }));
+    }
+
+    #[cfg(feature = "erased-msgpack")]
+    #[tokio::test]
+    async fn msgpack_round_trips() {
+        let res = ErasedResponse::msgpack(serde_json::json!({ "a": 1 })).into_response();
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/msgpack");
+        let bytes = body_bytes(res).await;
+        let value: serde_json::Value = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(value, serde_json::json!({ "a": 1 }));
+    }
+
+    #[cfg(feature = "erased-yaml")]
+    #[tokio::test]
+    async fn yaml_round_trips() {
+        let res = ErasedResponse::yaml(serde_json::json!({ "a": 1 })).into_response();
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/yaml");
+        let bytes = body_bytes(res).await;
+        let value: serde_json::Value = serde_yaml::from_slice(&bytes).unwrap();
+        assert_eq!(value, serde_json::json!({ "a": 1 }));
+    }
+
+    #[tokio::test]
+    async fn serialize_failure_produces_500_plain_text() {
+        struct NotSerializable;
+
+        impl Serialize for NotSerializable {
+            fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                Err(serde::ser::Error::custom("cannot serialize"))
+            }
+        }
+
+        let res = ErasedResponse::json(NotSerializable).into_response();
+        assert_eq!(res.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "text/plain");
+        assert_eq!(body_bytes(res).await, b"cannot serialize");
+    }
+}
@@ -0,0 +1,97 @@
+use std::convert::Infallible;
+
+use axum::{
+    body::{Bytes, Full},
+    http::{header, HeaderValue, Response, StatusCode},
+    response::IntoResponse,
+};
+use serde::{Serialize, Serializer};
+
+/// A response type that pairs a [`StatusCode`] with a serializable error payload, emitted as
+/// `{ "status": <u16>, "error": <message> }`.
+///
+/// This gives a canonical structured-error shape, rather than the raw `text/plain` fallback
+/// that [`ErasedJson`](super::ErasedJson) produces only on serialization failure.
+///
+/// # Example
+///
+/// ```rust
+/// # use axum::{http::StatusCode, response::IntoResponse};
+/// # use axum_extra::response::JsonError;
+/// async fn handler() -> impl IntoResponse {
+///     JsonError::not_found("no such user")
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct JsonError {
+    #[serde(serialize_with = "serialize_status_code")]
+    status: StatusCode,
+    error: String,
+}
+
+fn serialize_status_code<S>(status: &StatusCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u16(status.as_u16())
+}
+
+impl JsonError {
+    /// Create a `JsonError` from a status code and an error message.
+    pub fn new(status: StatusCode, error: impl Into<String>) -> Self {
+        Self {
+            status,
+            error: error.into(),
+        }
+    }
+
+    /// Create a `500 Internal Server Error` `JsonError`.
+    pub fn internal(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, error)
+    }
+
+    /// Create a `404 Not Found` `JsonError`.
+    pub fn not_found(error: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, error)
+    }
+}
+
+impl IntoResponse for JsonError {
+    type Body = Full<Bytes>;
+    type BodyError = Infallible;
+
+    fn into_response(self) -> Response<Self::Body> {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const APPLICATION_JSON: HeaderValue = HeaderValue::from_static("application/json");
+
+        let status = self.status;
+        // `status` and `error` are a `StatusCode` (serialized via an infallible `serialize_u16`
+        // call) and a `String`, so unlike `ErasedJson` there's no erased `Serialize` value that
+        // could fail here.
+        let bytes =
+            serde_json::to_vec(&self).expect("JsonError's fields always serialize successfully");
+
+        let mut res = Response::new(Full::from(bytes));
+        *res.status_mut() = status;
+        res.headers_mut()
+            .insert(header::CONTENT_TYPE, APPLICATION_JSON);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn not_found_serializes_to_expected_shape() {
+        let bytes = serde_json::to_vec(&JsonError::not_found("no such user")).unwrap();
+        assert_eq!(bytes, br#"{"status":404,"error":"no such user"}"#);
+    }
+
+    #[test]
+    fn internal_uses_500() {
+        let bytes = serde_json::to_vec(&JsonError::internal("boom")).unwrap();
+        assert_eq!(bytes, br#"{"status":500,"error":"boom"}"#);
+    }
+}
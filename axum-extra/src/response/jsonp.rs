@@ -0,0 +1,121 @@
+use std::convert::Infallible;
+
+use axum::{
+    body::{Bytes, Full},
+    http::{header, HeaderValue, Response, StatusCode},
+    response::IntoResponse,
+};
+use serde::Serialize;
+
+/// A response type that wraps serialized JSON in a JavaScript callback invocation, for
+/// cross-origin script-tag consumers.
+///
+/// The callback name is validated to contain only identifier-safe characters
+/// (`[A-Za-z_$][A-Za-z0-9_$.]*`); an unsafe name yields a `400 Bad Request` instead of being
+/// interpolated into the emitted script.
+///
+/// # Example
+///
+/// ```rust
+/// # use axum::{response::IntoResponse};
+/// # use axum_extra::response::Jsonp;
+/// async fn handler() -> impl IntoResponse {
+///     # let value = ();
+///     Jsonp::new("callback", &value)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Jsonp {
+    callback: Result<String, &'static str>,
+    result: serde_json::Result<Vec<u8>>,
+}
+
+impl Jsonp {
+    /// Create a `Jsonp` response by serializing a value and wrapping it in a call to
+    /// `callback`.
+    pub fn new<T: Serialize>(callback: impl Into<String>, val: T) -> Self {
+        let callback = callback.into();
+        let callback = if is_valid_callback(&callback) {
+            Ok(callback)
+        } else {
+            Err("callback name must match [A-Za-z_$][A-Za-z0-9_$.]*")
+        };
+
+        Self {
+            callback,
+            result: serde_json::to_vec(&val),
+        }
+    }
+}
+
+fn is_valid_callback(callback: &str) -> bool {
+    let mut chars = callback.chars();
+
+    let starts_ok = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$');
+    starts_ok
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$' || c == '.')
+}
+
+impl IntoResponse for Jsonp {
+    type Body = Full<Bytes>;
+    type BodyError = Infallible;
+
+    fn into_response(self) -> Response<Self::Body> {
+        #[allow(clippy::declare_interior_mutable_const)]
+        const APPLICATION_JAVASCRIPT: HeaderValue =
+            HeaderValue::from_static("application/javascript");
+
+        let callback = match self.callback {
+            Ok(callback) => callback,
+            Err(err) => {
+                return Response::builder()
+                    .status(StatusCode::BAD_REQUEST)
+                    .header(header::CONTENT_TYPE, "text/plain")
+                    .body(Full::from(err))
+                    .unwrap();
+            }
+        };
+
+        let json = match self.result {
+            Ok(json) => json,
+            Err(err) => {
+                return Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .header(header::CONTENT_TYPE, "text/plain")
+                    .body(Full::from(err.to_string()))
+                    .unwrap();
+            }
+        };
+
+        let mut body = Vec::with_capacity(callback.len() + json.len() + 2);
+        body.extend_from_slice(callback.as_bytes());
+        body.push(b'(');
+        body.extend_from_slice(&json);
+        body.extend_from_slice(b");");
+
+        let mut res = Response::new(Full::from(body));
+        res.headers_mut()
+            .insert(header::CONTENT_TYPE, APPLICATION_JAVASCRIPT);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_identifier_safe_callbacks() {
+        assert!(is_valid_callback("callback"));
+        assert!(is_valid_callback("_callback$1"));
+        assert!(is_valid_callback("ns.callback"));
+    }
+
+    #[test]
+    fn rejects_unsafe_callbacks() {
+        assert!(!is_valid_callback(""));
+        assert!(!is_valid_callback("1callback"));
+        assert!(!is_valid_callback("callback()"));
+        assert!(!is_valid_callback("callback;alert(1)"));
+    }
+}
@@ -0,0 +1,13 @@
+//! Additional response types.
+
+mod erased_json;
+mod erased_response;
+mod json_error;
+mod jsonp;
+mod negotiate;
+
+pub use self::erased_json::ErasedJson;
+pub use self::erased_response::ErasedResponse;
+pub use self::json_error::JsonError;
+pub use self::jsonp::Jsonp;
+pub use self::negotiate::{AcceptNegotiation, Negotiate, ResponseType};
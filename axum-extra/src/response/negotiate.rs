@@ -0,0 +1,266 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use axum::{
+    body::{Bytes, Full},
+    extract::{FromRequest, RequestParts},
+    http::{header, HeaderValue, Response, StatusCode},
+    response::IntoResponse,
+};
+use serde::Serialize;
+
+/// The content type chosen for a [`Negotiate`] response.
+///
+/// Produced by [`AcceptNegotiation`] after inspecting the request's `Accept` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseType {
+    /// Serialize the value as `application/json`.
+    Json,
+    /// Render the value's `Display` output as escaped `text/html`.
+    Html,
+    /// Render the value's `Display` output as `text/plain`.
+    PlainText,
+}
+
+/// An extractor that parses the request's `Accept` header and chooses a [`ResponseType`].
+///
+/// Construct a [`Negotiate`] response from the extracted value with [`Negotiate::new`].
+///
+/// # Example
+///
+/// ```rust
+/// # use axum::response::IntoResponse;
+/// # use axum_extra::response::{AcceptNegotiation, Negotiate};
+/// async fn handler(accept: AcceptNegotiation) -> impl IntoResponse {
+///     # let value = 0;
+///     Negotiate::new(accept, &value)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AcceptNegotiation(ResponseType);
+
+#[async_trait]
+impl<B> FromRequest<B> for AcceptNegotiation
+where
+    B: Send,
+{
+    type Rejection = Infallible;
+
+    async fn from_request(req: &mut RequestParts<B>) -> Result<Self, Self::Rejection> {
+        let response_type = req
+            .headers()
+            .and_then(|headers| headers.get(header::ACCEPT))
+            .and_then(|value| value.to_str().ok())
+            .map(choose_response_type)
+            .unwrap_or(ResponseType::Json);
+
+        Ok(Self(response_type))
+    }
+}
+
+/// A single media range parsed out of an `Accept` header, with its `q` value.
+struct MediaRange<'a> {
+    ty: &'a str,
+    subty: &'a str,
+    q: f32,
+}
+
+fn parse_accept(accept: &str) -> Vec<MediaRange<'_>> {
+    let mut ranges: Vec<_> = accept
+        .split(',')
+        .filter_map(|candidate| {
+            let mut parts = candidate.split(';');
+            let media_type = parts.next()?.trim();
+            let (ty, subty) = media_type.split_once('/')?;
+
+            let mut q = 1.0;
+            for param in parts {
+                let param = param.trim();
+                if let Some(value) = param.strip_prefix("q=") {
+                    q = value
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|q: &f32| q.is_finite())
+                        .unwrap_or(1.0);
+                }
+            }
+
+            Some(MediaRange {
+                ty: ty.trim(),
+                subty: subty.trim(),
+                q,
+            })
+        })
+        .collect();
+
+    ranges.sort_by(|a, b| b.q.partial_cmp(&a.q).unwrap_or(std::cmp::Ordering::Equal));
+    ranges
+}
+
+fn choose_response_type(accept: &str) -> ResponseType {
+    for range in parse_accept(accept) {
+        let response_type = match (range.ty, range.subty) {
+            ("*", "*") => Some(ResponseType::Json),
+            ("application", "*") | ("application", "json") => Some(ResponseType::Json),
+            ("text", "html") => Some(ResponseType::Html),
+            ("text", "plain") => Some(ResponseType::PlainText),
+            _ => None,
+        };
+
+        if let Some(response_type) = response_type {
+            return response_type;
+        }
+    }
+
+    ResponseType::Json
+}
+
+/// A response type that serializes a value as JSON, HTML, or plain text depending on the
+/// client's `Accept` header, as determined by [`AcceptNegotiation`].
+///
+/// The JSON body comes from `T`'s [`Serialize`] impl; the HTML and plain-text bodies come from
+/// `T`'s [`Display`] impl, since there's no single rendering that is simultaneously valid JSON,
+/// HTML, and plain text.
+///
+/// # Example
+///
+/// ```rust
+/// # use axum::response::IntoResponse;
+/// # use axum_extra::response::{AcceptNegotiation, Negotiate};
+/// async fn handler(accept: AcceptNegotiation) -> impl IntoResponse {
+///     # let value = 0;
+///     Negotiate::new(accept, &value)
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Negotiate {
+    response_type: ResponseType,
+    result: Result<Vec<u8>, String>,
+}
+
+impl Negotiate {
+    /// Create a `Negotiate` response from an [`AcceptNegotiation`] extraction and a value to
+    /// render.
+    pub fn new<T: Serialize + Display>(accept: AcceptNegotiation, val: T) -> Self {
+        let response_type = accept.0;
+        let result = match response_type {
+            ResponseType::Json => serde_json::to_vec(&val).map_err(|err| err.to_string()),
+            ResponseType::Html => {
+                Ok(format!("<pre>{}</pre>", html_escape(&val.to_string())).into_bytes())
+            }
+            ResponseType::PlainText => Ok(val.to_string().into_bytes()),
+        };
+
+        Self {
+            response_type,
+            result,
+        }
+    }
+}
+
+fn html_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl IntoResponse for Negotiate {
+    type Body = Full<Bytes>;
+    type BodyError = Infallible;
+
+    fn into_response(self) -> Response<Self::Body> {
+        let bytes = match self.result {
+            Ok(res) => res,
+            Err(err) => {
+                return Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .header(header::CONTENT_TYPE, "text/plain")
+                    .body(Full::from(err))
+                    .unwrap();
+            }
+        };
+
+        #[allow(clippy::declare_interior_mutable_const)]
+        const APPLICATION_JSON: HeaderValue = HeaderValue::from_static("application/json");
+        #[allow(clippy::declare_interior_mutable_const)]
+        const TEXT_HTML: HeaderValue = HeaderValue::from_static("text/html");
+        #[allow(clippy::declare_interior_mutable_const)]
+        const TEXT_PLAIN: HeaderValue = HeaderValue::from_static("text/plain");
+
+        let content_type = match self.response_type {
+            ResponseType::Json => APPLICATION_JSON,
+            ResponseType::Html => TEXT_HTML,
+            ResponseType::PlainText => TEXT_PLAIN,
+        };
+
+        let mut res = Response::new(Full::from(bytes));
+        res.headers_mut().insert(header::CONTENT_TYPE, content_type);
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_highest_q_value() {
+        let accept = "text/plain;q=0.5, text/html;q=0.9, application/json;q=0.1";
+        assert_eq!(choose_response_type(accept), ResponseType::Html);
+    }
+
+    #[test]
+    fn wildcard_falls_back_to_json() {
+        assert_eq!(choose_response_type("*/*"), ResponseType::Json);
+        assert_eq!(choose_response_type("application/*"), ResponseType::Json);
+    }
+
+    #[test]
+    fn unknown_type_falls_back_to_json() {
+        assert_eq!(choose_response_type("image/png"), ResponseType::Json);
+    }
+
+    #[test]
+    fn non_finite_q_values_fall_back_to_default() {
+        // Both q values are replaced by the default 1.0, so the first listed range wins.
+        let accept = "text/html;q=nan, application/json;q=nan";
+        assert_eq!(choose_response_type(accept), ResponseType::Html);
+    }
+
+    #[test]
+    fn html_escapes_reserved_characters() {
+        assert_eq!(
+            html_escape("<script>alert('hi')</script> & \"quotes\""),
+            "&lt;script&gt;alert(&#39;hi&#39;)&lt;/script&gt; &amp; &quot;quotes&quot;"
+        );
+    }
+
+    #[test]
+    fn renders_body_for_each_response_type() {
+        let json = Negotiate::new(AcceptNegotiation(ResponseType::Json), 42)
+            .result
+            .unwrap();
+        assert_eq!(json, b"42");
+
+        let html = Negotiate::new(AcceptNegotiation(ResponseType::Html), 42)
+            .result
+            .unwrap();
+        assert_eq!(html, b"<pre>42</pre>");
+
+        let plain = Negotiate::new(AcceptNegotiation(ResponseType::PlainText), 42)
+            .result
+            .unwrap();
+        assert_eq!(plain, b"42");
+    }
+}